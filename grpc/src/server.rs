@@ -1,17 +1,91 @@
 //! The gRPC server.
 //!
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
 use crate::{log, rpc::kv_store::*, SERVER_ADDR};
 use anyhow::Result;
+use futures_core::Stream;
+use tokio::sync::{mpsc, oneshot, Mutex};
+use tokio_stream::{wrappers::ReceiverStream, StreamExt};
 use tonic::{transport::Server, Request, Response, Status};
 
+/// Bound on the number of buffered, unconsumed events per `Watch` subscriber. A
+/// watcher that falls this far behind has its events dropped rather than
+/// blocking writers on a slow consumer.
+const WATCH_CHANNEL_CAPACITY: usize = 64;
+
+/// Revision history for a single namespaced key, oldest first.
+type Revisions = BTreeMap<u64, Vec<u8>>;
+
+/// MVCC-versioned storage keyed by (namespace, key).
+type Store = HashMap<(String, Vec<u8>), Revisions>;
+
+struct Watcher {
+    key_prefix: Vec<u8>,
+    sender: mpsc::Sender<Result<WatchEvent, Status>>,
+}
+
 // Define a struct KvStore that will store the state of our server.
 pub struct KvStore {
     // Task: Store the reference-counted lock
-    // Use tokio::sync::RwLock to synchronize access to the store HashMap<Vec<u8>, Vec<u8>>.
+    // Use tokio::sync::RwLock to synchronize access to the versioned Store.
     // Use std::sync::Arc to keep track of references to the lock in a thread-safe manner.
-    lock: std::sync::Arc<tokio::sync::RwLock<HashMap<Vec<u8>, Vec<u8>>>>,
+    lock: std::sync::Arc<tokio::sync::RwLock<Store>>,
+    // `Watch` only observes the default namespace; namespaced puts/deletes don't notify it.
+    watchers: std::sync::Arc<tokio::sync::RwLock<Vec<Watcher>>>,
+    started_at: Instant,
+    shutting_down: Arc<AtomicBool>,
+    shutdown_tx: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+}
+
+/// Applies a single versioned write to `store`, enforcing the compare-and-swap
+/// check on `expected_version` (0 means "write unconditionally") and returning
+/// the newly assigned revision.
+fn put_revision(
+    store: &mut Store,
+    namespace: String,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    expected_version: u64,
+) -> Result<u64, Status> {
+    let store_key = (namespace, key);
+    let latest_version = store
+        .get(&store_key)
+        .and_then(|revisions| revisions.keys().next_back().copied())
+        .unwrap_or(0);
+    if expected_version != 0 && expected_version != latest_version {
+        return Err(Status::new(
+            tonic::Code::FailedPrecondition,
+            "Key's latest revision does not match the expected version.",
+        ));
+    }
+    let new_version = latest_version + 1;
+    store.entry(store_key).or_default().insert(new_version, value);
+    Ok(new_version)
+}
+
+impl KvStore {
+    async fn notify(&self, key: &[u8], value: &[u8], kind: EventKind) {
+        let mut watchers = self.watchers.write().await;
+        watchers.retain(|watcher| {
+            if !key.starts_with(&watcher.key_prefix[..]) {
+                return true;
+            }
+            let event = WatchEvent {
+                key: key.to_vec(),
+                value: value.to_vec(),
+                kind: kind as i32,
+            };
+            match watcher.sender.try_send(Ok(event)) {
+                Ok(()) | Err(mpsc::error::TrySendError::Full(_)) => true,
+                Err(mpsc::error::TrySendError::Closed(_)) => false,
+            }
+        });
+    }
 }
 
 // Trait kv_store_server::KvStore in kv_store.rs
@@ -34,19 +108,33 @@ impl kv_store_server::KvStore for KvStore {
         }))
     }
 
-    async fn put(&self, request: Request<PutRequest>) -> Result<Response<()>, Status> {
-        let mut lock = self.lock.write().await;
-        let request_entry = request.into_inner();
-        let key = request_entry.key;
-        let value = request_entry.value;
-        lock.insert(key, value);
-        Ok(Response::new(()))
+    async fn put(&self, request: Request<PutRequest>) -> Result<Response<PutReply>, Status> {
+        if self.shutting_down.load(Ordering::SeqCst) {
+            return Err(Status::new(tonic::Code::Unavailable, "Server is shutting down."));
+        }
+        let PutRequest { key, value, namespace, version } = request.into_inner();
+        let new_version = {
+            let mut lock = self.lock.write().await;
+            put_revision(&mut lock, namespace.clone(), key.clone(), value.clone(), version)?
+        };
+        if namespace.is_empty() {
+            self.notify(&key, &value, EventKind::Put).await;
+        }
+        Ok(Response::new(PutReply { version: new_version }))
     }
 
     async fn get(&self, request: Request<GetRequest>) -> std::result::Result<Response<GetReply>, Status> {
+        let GetRequest { key, namespace, version } = request.into_inner();
         let lock = self.lock.read().await;
-        let key = request.into_inner().key;
-        match lock.get(&key) {
+        let revisions = lock
+            .get(&(namespace, key))
+            .ok_or_else(|| Status::new(tonic::Code::NotFound, "Key does not exist."))?;
+        let value = if version == 0 {
+            revisions.values().next_back()
+        } else {
+            revisions.get(&version)
+        };
+        match value {
             Some(value) => Ok(Response::new(GetReply {
                 value: value.clone(),
             })),
@@ -55,17 +143,171 @@ impl kv_store_server::KvStore for KvStore {
         }
     }
 
+    async fn delete(&self, request: Request<DeleteRequest>) -> Result<Response<DeleteReply>, Status> {
+        let DeleteRequest { key, namespace } = request.into_inner();
+        let existed = {
+            let mut lock = self.lock.write().await;
+            lock.remove(&(namespace.clone(), key.clone())).is_some()
+        };
+        if existed && namespace.is_empty() {
+            self.notify(&key, &[], EventKind::Delete).await;
+        }
+        Ok(Response::new(DeleteReply { existed }))
+    }
+
+    async fn batch_write(&self, request: Request<BatchWriteRequest>) -> Result<Response<BatchWriteReply>, Status> {
+        let mutations = request.into_inner().mutations;
+        // Every mutation is checked against a scratch view of what its CAS would see
+        // before anything is written to `lock`, so a failing mutation anywhere in the
+        // batch leaves the store untouched instead of partially applied.
+        let events = {
+            let mut lock = self.lock.write().await;
+
+            let mut staged_versions: HashMap<(String, Vec<u8>), u64> = HashMap::new();
+            for mutation in &mutations {
+                match &mutation.op {
+                    Some(mutation::Op::Put(put)) => {
+                        if self.shutting_down.load(Ordering::SeqCst) {
+                            return Err(Status::new(tonic::Code::Unavailable, "Server is shutting down."));
+                        }
+                        let key = (put.namespace.clone(), put.key.clone());
+                        let latest_version = staged_versions.get(&key).copied().unwrap_or_else(|| {
+                            lock.get(&key)
+                                .and_then(|revisions| revisions.keys().next_back().copied())
+                                .unwrap_or(0)
+                        });
+                        if put.version != 0 && put.version != latest_version {
+                            return Err(Status::new(
+                                tonic::Code::FailedPrecondition,
+                                "Key's latest revision does not match the expected version.",
+                            ));
+                        }
+                        staged_versions.insert(key, latest_version + 1);
+                    }
+                    Some(mutation::Op::Delete(delete)) => {
+                        // A delete resets the key's revision history, so a later Put in
+                        // this same batch must see latest_version 0, same as the apply
+                        // loop below will once the delete has actually been applied.
+                        staged_versions.insert((delete.namespace.clone(), delete.key.clone()), 0);
+                    }
+                    None => return Err(Status::new(tonic::Code::InvalidArgument, "Mutation must set put or delete.")),
+                }
+            }
+
+            let mut events = Vec::with_capacity(mutations.len());
+            for mutation in mutations {
+                match mutation.op.expect("validated above") {
+                    mutation::Op::Put(put) => {
+                        let namespace = put.namespace.clone();
+                        let key = put.key.clone();
+                        let value = put.value.clone();
+                        // `?` rather than `.expect()`: the scratch validation above should
+                        // always agree with this, but surface a Status instead of a panic
+                        // if that invariant is ever violated.
+                        put_revision(&mut lock, namespace.clone(), key.clone(), value.clone(), put.version)?;
+                        if namespace.is_empty() {
+                            events.push((key, value, EventKind::Put));
+                        }
+                    }
+                    mutation::Op::Delete(delete) => {
+                        lock.remove(&(delete.namespace.clone(), delete.key.clone()));
+                        if delete.namespace.is_empty() {
+                            events.push((delete.key, Vec::new(), EventKind::Delete));
+                        }
+                    }
+                }
+            }
+            events
+        };
+        for (key, value, kind) in events {
+            self.notify(&key, &value, kind).await;
+        }
+        Ok(Response::new(BatchWriteReply {}))
+    }
+
+    type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchEvent, Status>> + Send>>;
+
+    async fn watch(&self, request: Request<WatchRequest>) -> Result<Response<Self::WatchStream>, Status> {
+        let WatchRequest { key_prefix, send_initial } = request.into_inner();
+        let (tx, rx) = mpsc::channel(WATCH_CHANNEL_CAPACITY);
+
+        // Hold the store lock across the initial snapshot and the subscriber
+        // registration so a Put/Delete/BatchWrite can't land in the gap between
+        // them; otherwise it would be missed by both the snapshot and the feed.
+        let lock = self.lock.read().await;
+        // The snapshot is collected up front and streamed ahead of the live feed, so it
+        // never waits on the bounded channel and is not subject to WATCH_CHANNEL_CAPACITY.
+        let mut initial: Vec<Result<WatchEvent, Status>> = Vec::new();
+        if send_initial {
+            for ((_namespace, key), revisions) in lock
+                .iter()
+                .filter(|((namespace, key), _)| namespace.is_empty() && key.starts_with(&key_prefix[..]))
+            {
+                let Some(value) = revisions.values().next_back() else {
+                    continue;
+                };
+                let event = WatchEvent {
+                    key: key.clone(),
+                    value: value.clone(),
+                    kind: EventKind::Put as i32,
+                };
+                initial.push(Ok(event));
+            }
+        }
+        self.watchers.write().await.push(Watcher { key_prefix, sender: tx });
+        drop(lock);
+        let initial = tokio_stream::iter(initial);
+        Ok(Response::new(Box::pin(initial.chain(ReceiverStream::new(rx)))))
+    }
+
+    async fn status(&self, _request: Request<StatusRequest>) -> Result<Response<StatusResponse>, Status> {
+        let key_count = self.lock.read().await.len() as u64;
+        Ok(Response::new(StatusResponse {
+            healthy: !self.shutting_down.load(Ordering::SeqCst),
+            key_count,
+            uptime_ms: self.started_at.elapsed().as_millis() as u64,
+            details: HashMap::new(),
+        }))
+    }
+
+    async fn shutdown(&self, _request: Request<ShutdownRequest>) -> Result<Response<ShutdownReply>, Status> {
+        self.shutting_down.store(true, Ordering::SeqCst);
+        // Signal `serve_with_shutdown` to stop accepting new connections; in-flight
+        // requests on already-accepted connections are left to drain on their own.
+        if let Some(tx) = self.shutdown_tx.lock().await.take() {
+            let _ = tx.send(());
+        }
+        Ok(Response::new(ShutdownReply {}))
+    }
+
+    async fn list_versions(&self, request: Request<ListVersionsRequest>) -> Result<Response<ListVersionsReply>, Status> {
+        let ListVersionsRequest { key, namespace } = request.into_inner();
+        let lock = self.lock.read().await;
+        let versions = lock
+            .get(&(namespace, key))
+            .map(|revisions| revisions.keys().copied().collect())
+            .unwrap_or_default();
+        Ok(Response::new(ListVersionsReply { versions }))
+    }
+
 }
 
 pub async fn start() -> Result<()> {
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
     let svc = kv_store_server::KvStoreServer::new(KvStore {
-        lock: std::sync::Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+        lock: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+        watchers: Arc::new(tokio::sync::RwLock::new(Vec::new())),
+        started_at: Instant::now(),
+        shutting_down: Arc::new(AtomicBool::new(false)),
+        shutdown_tx: Arc::new(Mutex::new(Some(shutdown_tx))),
     });
 
     log::info!("Starting KV store server.");
     Server::builder()
         .add_service(svc)
-        .serve(SERVER_ADDR.parse().unwrap())
+        .serve_with_shutdown(SERVER_ADDR.parse().unwrap(), async {
+            let _ = shutdown_rx.await;
+        })
         .await?;
     Ok(())
 }